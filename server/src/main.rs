@@ -28,6 +28,89 @@ struct ImageQuery {
     width: u32,
     #[serde(default)]
     inverted: bool,
+    #[serde(default)]
+    format: Option<String>,
+    #[serde(default)]
+    transparent: bool,
+}
+
+/// The output formats the server knows how to name, in `?format=` and `Accept`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OutputFormat {
+    Svg,
+    Raster(image::ImageFormat),
+}
+
+impl OutputFormat {
+    fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "svg" => Self::Svg,
+            "png" => Self::Raster(image::ImageFormat::Png),
+            "jpeg" | "jpg" => Self::Raster(image::ImageFormat::Jpeg),
+            "webp" => Self::Raster(image::ImageFormat::WebP),
+            "gif" => Self::Raster(image::ImageFormat::Gif),
+            _ => return None,
+        })
+    }
+
+    fn content_type(self) -> &'static str {
+        match self {
+            Self::Svg => "image/svg+xml",
+            Self::Raster(image::ImageFormat::Png) => "image/png",
+            Self::Raster(image::ImageFormat::Jpeg) => "image/jpeg",
+            Self::Raster(image::ImageFormat::WebP) => "image/webp",
+            Self::Raster(image::ImageFormat::Gif) => "image/gif",
+            Self::Raster(_) => "application/octet-stream",
+        }
+    }
+
+    /// A short, ETag-safe tag distinguishing this format from others of the same hash/size.
+    fn tag(self) -> &'static str {
+        match self {
+            Self::Svg => "svg",
+            Self::Raster(image::ImageFormat::Png) => "png",
+            Self::Raster(image::ImageFormat::Jpeg) => "jpeg",
+            Self::Raster(image::ImageFormat::WebP) => "webp",
+            Self::Raster(image::ImageFormat::Gif) => "gif",
+            Self::Raster(_) => "bin",
+        }
+    }
+
+    fn writing_enabled(self) -> bool {
+        match self {
+            Self::Svg => true,
+            Self::Raster(format) => format.writing_enabled(),
+        }
+    }
+}
+
+/// Pick a response format from the `format` query parameter, falling back to the `Accept`
+/// header, and finally to PNG.
+///
+/// Returns `None` if `format` names something we don't recognise at all.
+fn negotiate_format(query: &ImageQuery, headers: &HeaderMap) -> Option<OutputFormat> {
+    if let Some(name) = &query.format {
+        return OutputFormat::from_name(name);
+    }
+
+    let accept = headers
+        .get(header::ACCEPT)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default();
+
+    for format in [
+        OutputFormat::Svg,
+        OutputFormat::Raster(image::ImageFormat::WebP),
+        OutputFormat::Raster(image::ImageFormat::Jpeg),
+        OutputFormat::Raster(image::ImageFormat::Gif),
+        OutputFormat::Raster(image::ImageFormat::Png),
+    ] {
+        if accept.contains(format.content_type()) {
+            return Some(format);
+        }
+    }
+
+    Some(OutputFormat::Raster(image::ImageFormat::Png))
 }
 
 #[axum::debug_handler]
@@ -36,26 +119,46 @@ async fn handler(
     Query(query): Query<ImageQuery>,
     path: Option<Path<String>>,
 ) -> Response {
-    let theme = Theme::default();
+    let theme = Theme {
+        background_alpha: if query.transparent { 0 } else { 255 },
+        ..Theme::default()
+    };
 
     let path = path.map_or(String::new(), |path| path.0);
 
-    if query.width > MAX_WIDTH {
+    let Some(format) = negotiate_format(&query, &headers) else {
         return (
             StatusCode::BAD_REQUEST,
-            format!("Invalid w parameter, must be less than {MAX_WIDTH}"),
+            format!("Invalid format parameter: {:?}", query.format),
         )
             .into_response();
-    }
-    let div = u32::from(theme.rows + 1) * 2;
-    if query.width % div != 0 {
+    };
+    if !format.writing_enabled() {
         return (
-            StatusCode::BAD_REQUEST,
-            format!("Invalid w parameter, must be evenly divisible by {div}"),
+            StatusCode::NOT_ACCEPTABLE,
+            format!("The {} encoder is not enabled on this server", format.tag()),
         )
             .into_response();
     }
 
+    if format != OutputFormat::Svg {
+        if query.width > MAX_WIDTH {
+            return (
+                StatusCode::BAD_REQUEST,
+                format!("Invalid w parameter, must be less than {MAX_WIDTH}"),
+            )
+                .into_response();
+        }
+        let div = u32::from(theme.rows + 1) * 2;
+        if query.width % div != 0 {
+            return (
+                StatusCode::BAD_REQUEST,
+                format!("Invalid w parameter, must be evenly divisible by {div}"),
+            )
+                .into_response();
+        }
+    }
+
     let hash = if path.len() == 32 && path.bytes().all(|b| b.is_ascii_hexdigit()) {
         std::array::from_fn(|index| {
             let s = &path[index * 2..index * 2 + 2];
@@ -67,13 +170,25 @@ async fn handler(
         hash.update(&path);
         hash.finalize()
     };
-    let etag = format!("{hash:x}");
+    let etag = format!(
+        "{hash:x}-{}{}",
+        format.tag(),
+        if query.transparent {
+            "-transparent"
+        } else {
+            ""
+        },
+    );
     if let Some(if_none_match) = headers
         .get(header::IF_NONE_MATCH)
         .and_then(|value| value.to_str().ok())
     {
         if if_none_match.contains(&etag) {
-            return (StatusCode::NOT_MODIFIED, [(header::ETAG, etag.as_str())]).into_response();
+            return (
+                StatusCode::NOT_MODIFIED,
+                [(header::ETAG, etag.as_str()), (header::VARY, "Accept")],
+            )
+                .into_response();
         }
     }
 
@@ -84,20 +199,27 @@ async fn handler(
         sigil
     };
 
-    let image = sigil.to_image(query.width);
-    let mut encoded = std::io::Cursor::new(vec![]);
-    if let Err(err) = image.write_to(&mut encoded, image::ImageFormat::Png) {
-        return (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response();
-    };
-    let encoded = encoded.into_inner();
-
-    let headers = [
+    let response_headers = [
         (header::ETAG, etag.as_str()),
+        (header::VARY, "Accept"),
         (header::CACHE_CONTROL, "max-age=315360000"),
-        (header::CONTENT_TYPE, "image/png"),
+        (header::CONTENT_TYPE, format.content_type()),
     ];
 
-    (headers, encoded).into_response()
+    match format {
+        OutputFormat::Svg => (response_headers, sigil.to_svg()).into_response(),
+        OutputFormat::Raster(format) => {
+            let encoded = if query.transparent {
+                sigil.to_encoded_rgba(query.width, format)
+            } else {
+                sigil.to_encoded(query.width, format)
+            };
+            match encoded {
+                Ok(encoded) => (response_headers, encoded).into_response(),
+                Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
+            }
+        }
+    }
 }
 
 #[derive(clap::Parser)]
@@ -76,14 +76,25 @@
 //! [axum]: https://docs.rs/axum
 //! [md-5]: https://docs.rs/md-5
 //! [Cupcake Sigil]: https://github.com/tent/sigil
+//!
+//! # Serialization
+//!
+//! Enable the `serde` feature to derive [`serde::Serialize`] and [`serde::Deserialize`] for
+//! [`Theme`] and [`Sigil`]. Colours are encoded as `#rrggbb` strings so the output stays
+//! human-diffable:
+//! ```toml
+//! [dependencies]
+//! sigil-rs = { version = "0.1", features = ["serde"] }
+//! ```
+//!
+//! For catching rendering regressions without pulling in serde, see [`Sigil::to_snapshot`].
 
 use std::fmt::Debug;
 use std::fmt::Display;
 use std::fmt::Write;
 
-use md5::Digest as _;
-
 pub use image::RgbImage;
+pub use image::RgbaImage;
 /// Colour type for configuring [Theme::foreground] and [Theme::background].
 pub type Rgb = image::Rgb<u8>;
 
@@ -98,16 +109,46 @@ const DEFAULT_FOREGROUND: [Rgb; 7] = [
     image::Rgb([141, 69, 170]),
 ];
 
+/// Controls how the hash bits are reflected across the grid to produce the final cell pattern.
+///
+/// Every mode drives only a fraction of the grid directly from the hash, then derives the rest
+/// by reflection, so sigils generated with the same hash look related across modes without
+/// changing the hashing itself.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Symmetry {
+    /// Mirror the left half of the grid onto the right half, row by row. This is the original,
+    /// and default, sigil look.
+    #[default]
+    MirrorHorizontal,
+    /// Mirror the top half of the grid onto the bottom half, column by column.
+    MirrorVertical,
+    /// Drive only the top-left quadrant from the hash and reflect it across both axes.
+    FourFold,
+    /// Drive half the grid from the hash and point-reflect each cell through the centre.
+    Rotational180,
+}
+
 /// Configure the way a sigil looks.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Theme {
     /// Supported values: 1-15 inclusive.
     pub rows: u16,
     /// Available foreground colours. Each sigil will use one foreground colour.
     ///
     /// Up to 256 different colours are supported.
+    #[cfg_attr(feature = "serde", serde(with = "rgb_hex::vec"))]
     pub foreground: Vec<Rgb>,
     /// Background colour.
+    #[cfg_attr(feature = "serde", serde(with = "rgb_hex"))]
     pub background: Rgb,
+    /// How the hash bits are reflected across the grid.
+    pub symmetry: Symmetry,
+    /// Opacity of the background colour in [`Sigil::to_image_rgba`], from `0` (fully
+    /// transparent) to `255` (fully opaque, the default).
+    ///
+    /// Ignored by [`Sigil::to_image`], which always renders an opaque image.
+    pub background_alpha: u8,
 }
 impl Default for Theme {
     fn default() -> Self {
@@ -115,6 +156,8 @@ impl Default for Theme {
             rows: 5,
             foreground: DEFAULT_FOREGROUND.to_vec(),
             background: Rgb::from([224, 224, 224]),
+            symmetry: Symmetry::default(),
+            background_alpha: 255,
         }
     }
 }
@@ -151,6 +194,110 @@ impl Cells {
         debug_assert!(index < self.capacity());
         self.bits[index / 8] |= 1 << (index % 8);
     }
+
+    fn to_grid(&self, rows: u16) -> CellGrid {
+        let n = usize::from(rows);
+        CellGrid {
+            rows,
+            filled: (0..n * n).map(|i| self.get(i)).collect(),
+        }
+    }
+
+    /// Builds a [`Cells`] bit set from a [`CellGrid`], validating that `rows` fits the bit set
+    /// and that `filled` has exactly `rows * rows` entries, so callers that construct a
+    /// `CellGrid` from untrusted data (snapshots, deserialization) can't trigger an
+    /// out-of-bounds panic in [`Cells::set`].
+    fn from_grid(grid: &CellGrid) -> Result<Self, String> {
+        if !(1..16).contains(&grid.rows) {
+            return Err(format!(
+                "rows must be between 1 and 15 inclusive, got {}",
+                grid.rows
+            ));
+        }
+        let expected = usize::from(grid.rows) * usize::from(grid.rows);
+        if grid.filled.len() != expected {
+            return Err(format!(
+                "expected {expected} cells for {} rows, got {}",
+                grid.rows,
+                grid.filled.len()
+            ));
+        }
+
+        let mut cells = Self::new();
+        for (index, &filled) in grid.filled.iter().enumerate() {
+            if filled {
+                cells.set(index);
+            }
+        }
+        Ok(cells)
+    }
+}
+
+/// A public, row-major view of a [`Sigil`]'s cell grid, for inspection or serialization without
+/// exposing the internal bit-packed representation.
+///
+/// ```
+/// use sigil_rs::Sigil;
+/// use sigil_rs::Theme;
+///
+/// let sigil = Sigil::generate(&Theme::default(), "my input value");
+/// let grid = sigil.grid();
+/// assert_eq!(grid.rows(), 5);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct CellGrid {
+    rows: u16,
+    filled: Vec<bool>,
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for CellGrid {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error as _;
+
+        #[derive(serde::Deserialize)]
+        struct CellGridData {
+            rows: u16,
+            filled: Vec<bool>,
+        }
+
+        let data = CellGridData::deserialize(deserializer)?;
+        let expected = usize::from(data.rows) * usize::from(data.rows);
+        if !(1..16).contains(&data.rows) {
+            return Err(D::Error::custom(format!(
+                "rows must be between 1 and 15 inclusive, got {}",
+                data.rows
+            )));
+        }
+        if data.filled.len() != expected {
+            return Err(D::Error::custom(format!(
+                "expected {expected} cells for {} rows, got {}",
+                data.rows,
+                data.filled.len()
+            )));
+        }
+
+        Ok(Self {
+            rows: data.rows,
+            filled: data.filled,
+        })
+    }
+}
+
+impl CellGrid {
+    /// The number of rows (and columns) in the grid.
+    pub fn rows(&self) -> u16 {
+        self.rows
+    }
+
+    /// Whether the cell at `(x, y)` is filled.
+    pub fn get(&self, x: u16, y: u16) -> bool {
+        self.filled[usize::from(y) * usize::from(self.rows) + usize::from(x)]
+    }
 }
 
 struct DisplayCells<'a>(&'a Cells, usize);
@@ -177,28 +324,140 @@ fn should_fill(index: usize, hash: &[u8]) -> bool {
     (hash[index / 8] >> (8 - ((index % 8) + 1))) & 1 == 1
 }
 
-fn generate_cells(size: usize, hash: &[u8]) -> Cells {
-    debug_assert_eq!(hash.len(), 15);
+// The source window is 15 bytes (hash[1..16]), i.e. 120 bits.
+const HASH_BITS: usize = 15 * 8;
 
-    let cols = (size / 2) + (size % 2);
+fn generate_cells(size: usize, hash: &[u8], symmetry: Symmetry) -> Cells {
+    debug_assert_eq!(hash.len(), 15);
 
+    let half = (size / 2) + (size % 2);
     let mut cells = Cells::new();
-    for i in (0..cols * size).filter(|i| should_fill(*i, hash)) {
-        let x = i / size;
-        let y = i % size;
 
-        cells.set(y * size + x);
-        // Mirror it.
-        cells.set(y * size + size - 1 - x);
+    match symmetry {
+        Symmetry::MirrorHorizontal => {
+            let driven = half * size;
+            debug_assert!(driven <= HASH_BITS);
+            for i in (0..driven).filter(|i| should_fill(*i, hash)) {
+                let x = i / size;
+                let y = i % size;
+
+                cells.set(y * size + x);
+                // Mirror it.
+                cells.set(y * size + size - 1 - x);
+            }
+        }
+        Symmetry::MirrorVertical => {
+            let driven = half * size;
+            debug_assert!(driven <= HASH_BITS);
+            for i in (0..driven).filter(|i| should_fill(*i, hash)) {
+                let x = i % size;
+                let y = i / size;
+
+                cells.set(y * size + x);
+                // Mirror it.
+                cells.set((size - 1 - y) * size + x);
+            }
+        }
+        Symmetry::FourFold => {
+            let driven = half * half;
+            debug_assert!(driven <= HASH_BITS);
+            for i in (0..driven).filter(|i| should_fill(*i, hash)) {
+                let x = i % half;
+                let y = i / half;
+
+                cells.set(y * size + x);
+                cells.set(y * size + size - 1 - x);
+                cells.set((size - 1 - y) * size + x);
+                cells.set((size - 1 - y) * size + size - 1 - x);
+            }
+        }
+        Symmetry::Rotational180 => {
+            let driven = (size * size / 2) + (size * size % 2);
+            debug_assert!(driven <= HASH_BITS);
+            for i in (0..driven).filter(|i| should_fill(*i, hash)) {
+                let x = i % size;
+                let y = i / size;
+
+                cells.set(y * size + x);
+                // Point-reflect it through the centre.
+                cells.set((size - 1 - y) * size + (size - 1 - x));
+            }
+        }
     }
 
     cells
 }
 
-fn md5(input: &[u8]) -> [u8; 16] {
-    let mut hash = md5::Md5::new();
-    hash.update(input);
-    hash.finalize().into()
+fn hex_color(rgb: Rgb) -> String {
+    let [r, g, b] = rgb.0;
+    format!("#{r:02x}{g:02x}{b:02x}")
+}
+
+fn parse_hex_rgb(s: &str) -> Result<Rgb, String> {
+    let digits = s.strip_prefix('#').unwrap_or(s);
+    if digits.len() != 6 || !digits.is_ascii() {
+        return Err(format!("{s:?} is not a #rrggbb colour"));
+    }
+
+    let mut channels = [0u8; 3];
+    for (channel, chunk) in channels.iter_mut().zip(digits.as_bytes().chunks(2)) {
+        *channel = u8::from_str_radix(std::str::from_utf8(chunk).unwrap(), 16)
+            .map_err(|_| format!("{s:?} is not a #rrggbb colour"))?;
+    }
+    Ok(Rgb::from(channels))
+}
+
+/// (De)serializes an [`Rgb`] as a `#rrggbb` string, for use with `#[serde(with = "rgb_hex")]`.
+#[cfg(feature = "serde")]
+mod rgb_hex {
+    use serde::de::Error as _;
+    use serde::Deserialize as _;
+    use serde::Deserializer;
+    use serde::Serialize as _;
+    use serde::Serializer;
+
+    use super::hex_color;
+    use super::parse_hex_rgb;
+    use super::Rgb;
+
+    pub fn serialize<S: Serializer>(rgb: &Rgb, serializer: S) -> Result<S::Ok, S::Error> {
+        hex_color(*rgb).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Rgb, D::Error> {
+        parse_hex_rgb(&String::deserialize(deserializer)?).map_err(D::Error::custom)
+    }
+
+    /// Same as the outer module, but for a `Vec<Rgb>`, for use with
+    /// `#[serde(with = "rgb_hex::vec")]`.
+    pub mod vec {
+        use serde::de::Error as _;
+        use serde::Deserialize as _;
+        use serde::Deserializer;
+        use serde::Serialize as _;
+        use serde::Serializer;
+
+        use super::hex_color;
+        use super::parse_hex_rgb;
+        use super::Rgb;
+
+        pub fn serialize<S: Serializer>(rgbs: &[Rgb], serializer: S) -> Result<S::Ok, S::Error> {
+            rgbs.iter()
+                .copied()
+                .map(hex_color)
+                .collect::<Vec<_>>()
+                .serialize(serializer)
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Vec<Rgb>, D::Error> {
+            Vec::<String>::deserialize(deserializer)?
+                .iter()
+                .map(|s| parse_hex_rgb(s).map_err(D::Error::custom))
+                .collect()
+        }
+    }
 }
 
 /// Represents a Sigil that can be rendered to an image.
@@ -214,6 +473,7 @@ fn md5(input: &[u8]) -> [u8; 16] {
 pub struct Sigil {
     foreground: Rgb,
     background: Rgb,
+    background_alpha: u8,
     rows: u16,
     cells: Cells,
 }
@@ -223,6 +483,7 @@ impl Debug for Sigil {
         f.debug_struct("Sigil")
             .field("foreground", &self.foreground)
             .field("background", &self.background)
+            .field("background_alpha", &self.background_alpha)
             .field("rows", &self.rows)
             .field("cells", &DisplayCells(&self.cells, self.rows as usize))
             .finish()
@@ -240,24 +501,46 @@ impl Sigil {
 
         let foreground = theme.pick_foreground(hash[0]);
         let background = theme.background;
-        let cells = generate_cells(theme.rows.into(), &hash[1..]);
+        let cells = generate_cells(theme.rows.into(), &hash[1..], theme.symmetry);
 
         Self {
             foreground,
             background,
+            background_alpha: theme.background_alpha,
             rows: theme.rows,
             cells,
         }
     }
 
+    /// Generate a sigil by hashing an input with a custom [`digest::Digest`] implementation.
+    ///
+    /// This is handy when a server wants the same kind of hash it already uses for another
+    /// purpose (say, SHA-256 for email addresses), or simply a stronger hash than MD5. The input
+    /// is always hashed here with a fresh `D`; a digest that was already computed can be passed
+    /// to [`Sigil::from_hash`] as its first 16 bytes instead.
+    ///
+    /// # Panics
+    /// Panics if the theme has an invalid `rows` value, or if `D` produces fewer than 16 bytes
+    /// of output.
+    pub fn generate_with<D: digest::Digest>(theme: &Theme, input: impl AsRef<[u8]>) -> Self {
+        assert!(D::output_size() >= 16);
+
+        let mut hash = D::new();
+        hash.update(input.as_ref());
+        let hash = hash.finalize();
+
+        let mut bytes = [0u8; 16];
+        bytes.copy_from_slice(&hash[..16]);
+
+        Self::from_hash(theme, bytes)
+    }
+
     /// Generate a sigil by hashing an input.
     ///
     /// # Panics
     /// Panics if the theme has an invalid `rows` value.
     pub fn generate(theme: &Theme, input: impl AsRef<[u8]>) -> Self {
-        let hash = md5(input.as_ref());
-
-        Self::from_hash(theme, hash)
+        Self::generate_with::<md5::Md5>(theme, input)
     }
 
     /// Swap foreground and background colours.
@@ -302,12 +585,355 @@ impl Sigil {
         })
     }
 
+    /// Create a square image of the given size, with the background rendered at
+    /// [`Theme::background_alpha`] opacity instead of flattened onto it.
+    ///
+    /// This is useful for compositing the sigil over an arbitrary page background. Use
+    /// [`Sigil::to_image`] if you just want an opaque image.
+    ///
+    /// # Panics
+    /// Panics if `size` is not a multiple of `(rows + 1) * 2`.
+    pub fn to_image_rgba(&self, size: u32) -> RgbaImage {
+        let rows = u32::from(self.rows);
+        assert_eq!(size % ((rows + 1) * 2), 0);
+
+        let cell_size = size / (rows + 1);
+        let padding = cell_size / 2;
+
+        let [r, g, b] = self.background.0;
+        let background = image::Rgba([r, g, b, self.background_alpha]);
+        let [r, g, b] = self.foreground.0;
+        let foreground = image::Rgba([r, g, b, 255]);
+
+        RgbaImage::from_fn(size, size, |x, y| {
+            if x < padding || x >= size - padding || y < padding || y >= size - padding {
+                return background;
+            }
+
+            let x = (x - padding) / cell_size;
+            let y = (y - padding) / cell_size;
+            let cell_index = y * rows + x;
+            if self.cells.get(cell_index as usize) {
+                foreground
+            } else {
+                background
+            }
+        })
+    }
+
+    /// Render and encode the sigil to bytes in the given [`image::ImageFormat`], centralising
+    /// the `write_to` call so callers (e.g. an HTTP server) don't need to reach for
+    /// `std::io::Cursor` themselves.
+    ///
+    /// # Errors
+    /// Returns [`EncodeError::UnsupportedFormat`] if `format` wasn't compiled into the `image`
+    /// crate's encoders, or [`EncodeError::Encode`] if encoding otherwise fails.
+    ///
+    /// # Panics
+    /// Panics if `size` is not a multiple of `(rows + 1) * 2`.
+    pub fn to_encoded(
+        &self,
+        size: u32,
+        format: image::ImageFormat,
+    ) -> Result<Vec<u8>, EncodeError> {
+        if !format.writing_enabled() {
+            return Err(EncodeError::UnsupportedFormat(format));
+        }
+
+        let image = self.to_image(size);
+        let mut encoded = std::io::Cursor::new(Vec::new());
+        image
+            .write_to(&mut encoded, format)
+            .map_err(EncodeError::Encode)?;
+        Ok(encoded.into_inner())
+    }
+
+    /// Same as [`Sigil::to_encoded`], but preserving the alpha channel via
+    /// [`Sigil::to_image_rgba`].
+    ///
+    /// # Errors
+    /// Returns [`EncodeError::UnsupportedFormat`] if `format` wasn't compiled into the `image`
+    /// crate's encoders, or [`EncodeError::Encode`] if encoding otherwise fails.
+    ///
+    /// # Panics
+    /// Panics if `size` is not a multiple of `(rows + 1) * 2`.
+    pub fn to_encoded_rgba(
+        &self,
+        size: u32,
+        format: image::ImageFormat,
+    ) -> Result<Vec<u8>, EncodeError> {
+        if !format.writing_enabled() {
+            return Err(EncodeError::UnsupportedFormat(format));
+        }
+
+        let image = self.to_image_rgba(size);
+        let mut encoded = std::io::Cursor::new(Vec::new());
+        image
+            .write_to(&mut encoded, format)
+            .map_err(EncodeError::Encode)?;
+        Ok(encoded.into_inner())
+    }
+
+    /// Render a scalable SVG image.
+    ///
+    /// Unlike [`Sigil::to_image`], the result is resolution-independent: there's no "right"
+    /// size to pick, so consumers scale the `viewBox` however they like with CSS or the `width`
+    /// and `height` attributes.
+    ///
+    /// Honours [`Theme::background_alpha`] like [`Sigil::to_image_rgba`] does: the background
+    /// `<rect>` is omitted entirely when the alpha is `0`, given a `fill-opacity` when the alpha
+    /// is between `1` and `254`, and drawn without one when the alpha is `255`.
+    pub fn to_svg(&self) -> String {
+        let rows = usize::from(self.rows);
+        let n = rows + 1;
+        let padding = 0.5;
+
+        let mut svg = String::new();
+        let _ = write!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 {n} {n}">"#
+        );
+        if self.background_alpha == 255 {
+            let _ = write!(
+                svg,
+                r#"<rect width="{n}" height="{n}" fill="{}"/>"#,
+                hex_color(self.background)
+            );
+        } else if self.background_alpha > 0 {
+            let _ = write!(
+                svg,
+                r#"<rect width="{n}" height="{n}" fill="{}" fill-opacity="{}"/>"#,
+                hex_color(self.background),
+                f64::from(self.background_alpha) / 255.0,
+            );
+        }
+
+        let foreground = hex_color(self.foreground);
+        for y in 0..rows {
+            let mut x = 0;
+            while x < rows {
+                if !self.cells.get(y * rows + x) {
+                    x += 1;
+                    continue;
+                }
+
+                let start = x;
+                while x < rows && self.cells.get(y * rows + x) {
+                    x += 1;
+                }
+
+                let _ = write!(
+                    svg,
+                    r#"<rect x="{}" y="{}" width="{}" height="1" fill="{foreground}"/>"#,
+                    start as f64 + padding,
+                    y as f64 + padding,
+                    x - start,
+                );
+            }
+        }
+
+        svg.push_str("</svg>");
+        svg
+    }
+
+    /// Returns a public, row-major view of the cell grid.
+    pub fn grid(&self) -> CellGrid {
+        self.cells.to_grid(self.rows)
+    }
+
+    /// Serialize the sigil to a stable, human-diffable textual format: the resolved `rows`,
+    /// `foreground`, `background` and `background_alpha` values, followed by the `X`/`-` cell
+    /// grid.
+    ///
+    /// This is meant for snapshot/regression testing (see `tests/ref/`), not as a user-facing
+    /// format — use the `serde` feature if you need a format to round-trip through other tools.
+    pub fn to_snapshot(&self) -> String {
+        let mut out = format!(
+            "rows={}\nforeground={}\nbackground={}\nbackground_alpha={}\n",
+            self.rows,
+            hex_color(self.foreground),
+            hex_color(self.background),
+            self.background_alpha,
+        );
+        let _ = write!(out, "{}", DisplayCells(&self.cells, self.rows.into()));
+        out
+    }
+
+    /// Parse a snapshot produced by [`Sigil::to_snapshot`].
+    ///
+    /// # Errors
+    /// Returns an error if `snapshot` isn't in the format [`Sigil::to_snapshot`] produces.
+    pub fn from_snapshot(snapshot: &str) -> Result<Self, SnapshotParseError> {
+        let mut lines = snapshot.lines();
+
+        let rows = snapshot_header(lines.next(), "rows")?
+            .parse::<u16>()
+            .map_err(|_| SnapshotParseError("rows is not a valid number".to_owned()))?;
+        let foreground = parse_hex_rgb(snapshot_header(lines.next(), "foreground")?)
+            .map_err(SnapshotParseError)?;
+        let background = parse_hex_rgb(snapshot_header(lines.next(), "background")?)
+            .map_err(SnapshotParseError)?;
+        let background_alpha = snapshot_header(lines.next(), "background_alpha")?
+            .parse::<u8>()
+            .map_err(|_| SnapshotParseError("background_alpha is not a valid number".to_owned()))?;
+
+        let mut filled = Vec::with_capacity(usize::from(rows) * usize::from(rows));
+        let mut grid_rows = 0u16;
+        for line in lines {
+            if line.len() != usize::from(rows) {
+                return Err(SnapshotParseError(format!(
+                    "row {grid_rows} has the wrong width"
+                )));
+            }
+            for ch in line.chars() {
+                match ch {
+                    'X' => filled.push(true),
+                    '-' => filled.push(false),
+                    _ => {
+                        return Err(SnapshotParseError(format!(
+                            "unexpected character {ch:?} in the cell grid"
+                        )))
+                    }
+                }
+            }
+            grid_rows += 1;
+        }
+        if grid_rows != rows {
+            return Err(SnapshotParseError(format!(
+                "expected {rows} grid rows, got {grid_rows}"
+            )));
+        }
+
+        Ok(Self {
+            foreground,
+            background,
+            background_alpha,
+            rows,
+            cells: Cells::from_grid(&CellGrid { rows, filled }).map_err(SnapshotParseError)?,
+        })
+    }
+
     #[cfg(test)]
     fn display(&self) -> DisplayCells<'_> {
         DisplayCells(&self.cells, self.rows.into())
     }
 }
 
+fn snapshot_header<'a>(line: Option<&'a str>, key: &str) -> Result<&'a str, SnapshotParseError> {
+    line.and_then(|line| line.strip_prefix(key))
+        .and_then(|rest| rest.strip_prefix('='))
+        .ok_or_else(|| SnapshotParseError(format!("expected a `{key}=...` line")))
+}
+
+/// Error returned by [`Sigil::from_snapshot`] when the input isn't a valid snapshot.
+#[derive(Debug, Clone)]
+pub struct SnapshotParseError(String);
+
+impl Display for SnapshotParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid sigil snapshot: {}", self.0)
+    }
+}
+
+impl std::error::Error for SnapshotParseError {}
+
+/// Error returned by [`Sigil::to_encoded`].
+#[derive(Debug)]
+pub enum EncodeError {
+    /// The [`image`] crate wasn't compiled with an encoder for this format.
+    UnsupportedFormat(image::ImageFormat),
+    /// The [`image`] crate failed to encode the image.
+    Encode(image::ImageError),
+}
+
+impl Display for EncodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnsupportedFormat(format) => {
+                write!(
+                    f,
+                    "the {format:?} encoder is not compiled into the image crate"
+                )
+            }
+            Self::Encode(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for EncodeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::UnsupportedFormat(_) => None,
+            Self::Encode(err) => Some(err),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Sigil {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::Serialize as _;
+
+        #[derive(serde::Serialize)]
+        struct SigilData {
+            #[serde(with = "rgb_hex")]
+            foreground: Rgb,
+            #[serde(with = "rgb_hex")]
+            background: Rgb,
+            background_alpha: u8,
+            rows: u16,
+            cells: CellGrid,
+        }
+
+        SigilData {
+            foreground: self.foreground,
+            background: self.background,
+            background_alpha: self.background_alpha,
+            rows: self.rows,
+            cells: self.grid(),
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Sigil {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error as _;
+        use serde::Deserialize as _;
+
+        #[derive(serde::Deserialize)]
+        struct SigilData {
+            #[serde(with = "rgb_hex")]
+            foreground: Rgb,
+            #[serde(with = "rgb_hex")]
+            background: Rgb,
+            background_alpha: u8,
+            rows: u16,
+            cells: CellGrid,
+        }
+
+        let data = SigilData::deserialize(deserializer)?;
+        if data.cells.rows != data.rows {
+            return Err(D::Error::custom("cells grid size does not match rows"));
+        }
+
+        Ok(Self {
+            foreground: data.foreground,
+            background: data.background,
+            background_alpha: data.background_alpha,
+            rows: data.rows,
+            cells: Cells::from_grid(&data.cells).map_err(D::Error::custom)?,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use indoc::indoc;
@@ -343,6 +969,185 @@ mod tests {
         );
     }
 
+    #[test]
+    fn snapshot_round_trips() {
+        let sigil = Sigil::generate(&Theme::default(), "test");
+        let snapshot = sigil.to_snapshot();
+
+        let parsed = Sigil::from_snapshot(&snapshot).expect("valid snapshot failed to parse");
+        assert_eq!(parsed.to_snapshot(), snapshot);
+    }
+
+    #[test]
+    fn snapshot_parse_errors() {
+        assert!(Sigil::from_snapshot("not a snapshot").is_err());
+        assert!(Sigil::from_snapshot("rows=5\nforeground=#ffffff\nbackground=#000000\n").is_err());
+
+        let sigil = Sigil::generate(&Theme::default(), "test");
+        let mut truncated = sigil.to_snapshot();
+        truncated.pop(); // drop the last grid row's trailing newline, then a whole row below
+        for _ in 0..6 {
+            truncated.pop();
+        }
+        assert!(Sigil::from_snapshot(&truncated).is_err());
+    }
+
+    #[test]
+    fn snapshot_rejects_out_of_range_rows_instead_of_panicking() {
+        let mut snapshot =
+            "rows=17\nforeground=#ffffff\nbackground=#000000\nbackground_alpha=255\n".to_owned();
+        for _ in 0..17 {
+            snapshot.push_str(&"-".repeat(17));
+            snapshot.push('\n');
+        }
+
+        assert!(Sigil::from_snapshot(&snapshot).is_err());
+    }
+
+    #[test]
+    fn generate_with_custom_digest() {
+        assert_eq!(
+            Sigil::generate_with::<md5::Md5>(&Theme::default(), "test")
+                .display()
+                .to_string(),
+            Sigil::generate(&Theme::default(), "test")
+                .display()
+                .to_string(),
+        );
+    }
+
+    #[test]
+    fn to_encoded_round_trips_through_the_image_crate() {
+        let sigil = Sigil::generate(&Theme::default(), "test");
+
+        let png = sigil
+            .to_encoded(240, image::ImageFormat::Png)
+            .expect("PNG encoding failed");
+        let decoded = image::load_from_memory_with_format(&png, image::ImageFormat::Png)
+            .expect("PNG decoding failed");
+        assert_eq!(decoded.to_rgb8(), sigil.to_image(240));
+
+        let png_rgba = sigil
+            .to_encoded_rgba(240, image::ImageFormat::Png)
+            .expect("PNG encoding failed");
+        let decoded_rgba = image::load_from_memory_with_format(&png_rgba, image::ImageFormat::Png)
+            .expect("PNG decoding failed");
+        assert_eq!(decoded_rgba.to_rgba8(), sigil.to_image_rgba(240));
+    }
+
+    #[test]
+    fn to_svg_merges_adjacent_cells() {
+        let sigil = Sigil::generate(&Theme::default(), "test");
+        assert_eq!(
+            sigil.to_svg(),
+            concat!(
+                r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 6 6">"#,
+                r#"<rect width="6" height="6" fill="#e0e0e0"/>"#,
+                r#"<rect x="0.5" y="0.5" width="5" height="1" fill="#e279ea"/>"#,
+                r#"<rect x="1.5" y="1.5" width="1" height="1" fill="#e279ea"/>"#,
+                r#"<rect x="3.5" y="1.5" width="1" height="1" fill="#e279ea"/>"#,
+                r#"<rect x="1.5" y="2.5" width="3" height="1" fill="#e279ea"/>"#,
+                r#"<rect x="0.5" y="4.5" width="5" height="1" fill="#e279ea"/>"#,
+                "</svg>",
+            ),
+        );
+    }
+
+    #[test]
+    fn symmetry_modes() {
+        let theme = Theme {
+            symmetry: Symmetry::MirrorVertical,
+            ..Theme::default()
+        };
+        assert_eq!(
+            Sigil::generate(&theme, "test").display().to_string(),
+            indoc! {"
+                X---X
+                XXX-X
+                X-X-X
+                XXX-X
+                X---X
+            "}
+        );
+
+        let theme = Theme {
+            symmetry: Symmetry::FourFold,
+            ..Theme::default()
+        };
+        assert_eq!(
+            Sigil::generate(&theme, "test").display().to_string(),
+            indoc! {"
+                X---X
+                -XXX-
+                XX-XX
+                -XXX-
+                X---X
+            "}
+        );
+
+        let theme = Theme {
+            symmetry: Symmetry::Rotational180,
+            ..Theme::default()
+        };
+        assert_eq!(
+            Sigil::generate(&theme, "test").display().to_string(),
+            indoc! {"
+                X---X
+                XXX-X
+                X-X-X
+                X-XXX
+                X---X
+            "}
+        );
+    }
+
+    #[test]
+    fn to_image_rgba_honours_background_alpha() {
+        let theme = Theme {
+            background_alpha: 128,
+            ..Theme::default()
+        };
+        let sigil = Sigil::generate(&theme, "test");
+        let image = sigil.to_image_rgba(240);
+
+        // The top-left corner is padding, so always background.
+        assert_eq!(image.get_pixel(0, 0).0[3], 128);
+        // The cell at (0, 0) is filled for this input (see `same_as_cupcake`), so the centre of
+        // the first cell is foreground, which `to_image_rgba` always renders fully opaque.
+        let cell_size = 240 / 6;
+        let first_cell_centre = cell_size;
+        assert_eq!(
+            image.get_pixel(first_cell_centre, first_cell_centre).0[3],
+            255
+        );
+    }
+
+    #[test]
+    fn to_svg_honours_background_alpha() {
+        let opaque = Sigil::generate(&Theme::default(), "test");
+        assert!(opaque.to_svg().contains(r#"<rect width="6" height="6" fill="#e0e0e0"/>"#));
+
+        let transparent = Sigil::generate(
+            &Theme {
+                background_alpha: 0,
+                ..Theme::default()
+            },
+            "test",
+        );
+        assert!(!transparent.to_svg().contains("#e0e0e0"));
+
+        let half = Sigil::generate(
+            &Theme {
+                background_alpha: 128,
+                ..Theme::default()
+            },
+            "test",
+        );
+        assert!(half
+            .to_svg()
+            .contains(r#"fill="#e0e0e0" fill-opacity="0.5019607843137255"/>"#));
+    }
+
     #[test]
     fn even_rows() {
         let theme = Theme {
@@ -0,0 +1,47 @@
+//! Reference tests: regenerate a sigil from a recorded input/theme and compare the textual
+//! snapshot byte-for-byte against a golden file. Catches accidental drift in the cell-generation
+//! or colour-selection algorithms.
+
+use sigil_rs::Sigil;
+use sigil_rs::Theme;
+
+fn parse_header<'a>(line: Option<&'a str>, key: &str) -> &'a str {
+    line.and_then(|line| line.strip_prefix(key))
+        .and_then(|rest| rest.strip_prefix(" = "))
+        .unwrap_or_else(|| panic!("expected a `{key} = ...` header line, got {line:?}"))
+}
+
+#[test]
+fn ref_snapshots_match() {
+    let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/ref");
+    for entry in std::fs::read_dir(dir).expect("read tests/ref") {
+        let path = entry.expect("read dir entry").path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some("snap") {
+            continue;
+        }
+
+        let contents = std::fs::read_to_string(&path).expect("read golden file");
+        let (header, snapshot) = contents
+            .split_once("\n\n")
+            .expect("golden file is missing the blank line between header and snapshot");
+
+        let mut lines = header.lines();
+        let input = parse_header(lines.next(), "input");
+        let rows = parse_header(lines.next(), "rows")
+            .parse()
+            .expect("rows header is not a valid number");
+
+        let theme = Theme {
+            rows,
+            ..Theme::default()
+        };
+        let sigil = Sigil::generate(&theme, input);
+
+        assert_eq!(
+            sigil.to_snapshot(),
+            snapshot,
+            "{} regenerated a different sigil than its golden snapshot",
+            path.display(),
+        );
+    }
+}